@@ -0,0 +1,146 @@
+//! This module defines newtype wrappers for Kafka's array primitives
+//! (`array` and the compact/flexible-versions `compact array`). These
+//! wrappers implement Serde's `Serialize` and `Deserialize` traits,
+//! providing a Kafka-compatible representation of the length-prefixed,
+//! nullable wire-format collection primitives.
+//!
+//! # Important
+//! - Although these types implement `Serialize` and `Deserialize`, the actual
+//!   **length-prefix encoding** you produce or consume will depend on the
+//!   underlying format and serializer. For direct, low-level I/O, see the
+//!   [`KafkaSerializer::write_array`][crate::KafkaSerializer::write_array] /
+//!   [`KafkaSerializer::write_compact_array`][crate::KafkaSerializer::write_compact_array]
+//!   and
+//!   [`KafkaDeserializer::read_array`][crate::KafkaDeserializer::read_array] /
+//!   [`KafkaDeserializer::read_compact_array`][crate::KafkaDeserializer::read_compact_array]
+//!   methods in `io.rs` within this crate.
+
+use crate::Vec;
+use serde::{Deserialize, Serialize};
+
+/// A newtype for Kafka's non-compact `array` type: an `i32` big-endian
+/// element count (`-1` meaning null) followed by each element in sequence.
+///
+/// # Serialization
+/// - Uses standard `Option<Vec<T>>` Serde serialization by default.
+/// - For low-level byte I/O using Kafka's length-prefixed array encoding,
+///   use
+///   [`KafkaSerializer::write_array`][crate::KafkaSerializer::write_array]
+///   and
+///   [`KafkaDeserializer::read_array`][crate::KafkaDeserializer::read_array].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KafkaArray<T>(pub Option<Vec<T>>);
+
+impl<T: Serialize> Serialize for KafkaArray<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for KafkaArray<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let val = Option::<Vec<T>>::deserialize(deserializer)?;
+        Ok(KafkaArray(val))
+    }
+}
+
+/// A newtype for Kafka's flexible-versions `compact array` type: an
+/// unsigned varint equal to `len + 1` (where `0` means null) followed by
+/// each element in sequence.
+///
+/// # Serialization
+/// - Uses standard `Option<Vec<T>>` Serde serialization by default.
+/// - For low-level byte I/O using Kafka's compact-array encoding, use
+///   [`KafkaSerializer::write_compact_array`][crate::KafkaSerializer::write_compact_array]
+///   and
+///   [`KafkaDeserializer::read_compact_array`][crate::KafkaDeserializer::read_compact_array].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KafkaCompactArray<T>(pub Option<Vec<T>>);
+
+impl<T: Serialize> Serialize for KafkaCompactArray<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for KafkaCompactArray<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let val = Option::<Vec<T>>::deserialize(deserializer)?;
+        Ok(KafkaCompactArray(val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! This module contains unit tests that verify round-trip behavior
+    //! using the custom serializer/deserializer found in `io.rs`.
+
+    use crate::{KafkaDeserializer, KafkaSerializer};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_array_roundtrip() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_array(Some(&[1i32, 2, 3]), |s, v| s.write_i32(*v))
+                .expect("Failed to write array");
+        }
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        let decoded = de
+            .read_array(16, |d| d.read_i32())
+            .expect("Failed to read array");
+        assert_eq!(decoded, Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_array_null_roundtrip() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_array::<i32>(None, |s, v| s.write_i32(*v))
+                .expect("Failed to write null array");
+        }
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        let decoded = de
+            .read_array(16, |d| d.read_i32())
+            .expect("Failed to read null array");
+        assert_eq!(decoded, None);
+    }
+
+    #[test]
+    fn test_compact_array_roundtrip() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_compact_array(Some(&[10i32, 20]), |s, v| s.write_i32(*v))
+                .expect("Failed to write compact array");
+        }
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        let decoded = de
+            .read_compact_array(16, |d| d.read_i32())
+            .expect("Failed to read compact array");
+        assert_eq!(decoded, Some(vec![10, 20]));
+    }
+
+    #[test]
+    fn test_array_rejects_over_cap_length() {
+        // i32 length of 1000, which exceeds our max_elements cap below.
+        let buffer = vec![0x00, 0x00, 0x03, 0xE8];
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        let result = de.read_array(16, |d| d.read_i32());
+        assert!(result.is_err());
+    }
+}
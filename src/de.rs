@@ -0,0 +1,545 @@
+//! A `serde::Deserializer` backend that decodes values directly from
+//! Kafka's wire format, backed by [`KafkaDeserializer`].
+//!
+//! Mirrors the `de` module structure used by crates like `bincode`: a
+//! [`Deserializer`] type plus a top-level [`from_reader`] entry point, so a
+//! request/response struct can simply `#[derive(Deserialize)]` and get
+//! correct big-endian integers, length-prefixed strings, and arrays for
+//! free.
+//!
+//! Like [`crate::ser`], this is not a self-describing format: struct
+//! fields are read back positionally (there is no field-name or
+//! field-count prefix on the wire), and `Option<T>` uses a presence byte
+//! rather than any of Kafka's native nullable sentinels — see the
+//! `ser` module docs for why. Enum variants are read by an `i32`
+//! big-endian variant index, matching what [`crate::ser::Serializer`]
+//! writes.
+
+use crate::error::{Error, Result};
+use crate::io::KafkaDeserializer;
+use serde::de::value::U32Deserializer;
+use serde::de::{self, Visitor};
+use serde::Deserialize;
+use std::io::Read;
+
+/// Default cap on a single `bytes`/`ByteBuf` field's declared length, used
+/// by [`from_reader`]/[`from_bytes`]. A field declaring more than this many
+/// bytes is rejected rather than driving an unbounded allocation; callers
+/// that legitimately need larger fields can opt into a higher cap with
+/// [`from_reader_with_max_len`]/[`from_bytes_with_max_len`].
+pub const DEFAULT_MAX_LEN: usize = 16 * 1024 * 1024;
+
+/// A `serde::Deserializer` that reads values from an underlying `Read`
+/// stream using the Kafka wire format.
+pub struct Deserializer<R: Read> {
+    de: KafkaDeserializer<R>,
+    /// Cap passed to [`KafkaDeserializer::read_bytes`] for `bytes`/`ByteBuf`
+    /// fields. See [`DEFAULT_MAX_LEN`].
+    max_len: usize,
+}
+
+impl<R: Read> Deserializer<R> {
+    /// Create a new `Deserializer` that reads from the given `Read`
+    /// implementor, capping `bytes`/`ByteBuf` fields at [`DEFAULT_MAX_LEN`].
+    pub fn new(reader: R) -> Self {
+        Deserializer {
+            de: KafkaDeserializer::new(reader),
+            max_len: DEFAULT_MAX_LEN,
+        }
+    }
+
+    /// Create a new `Deserializer` like [`new`][Self::new], but capping
+    /// `bytes`/`ByteBuf` fields' declared length at `max_len` instead of
+    /// [`DEFAULT_MAX_LEN`].
+    pub fn with_max_len(reader: R, max_len: usize) -> Self {
+        Deserializer {
+            de: KafkaDeserializer::new(reader),
+            max_len,
+        }
+    }
+}
+
+/// Deserializes a `T` from `reader` using the Kafka wire format, capping
+/// `bytes`/`ByteBuf` fields' declared length at [`DEFAULT_MAX_LEN`].
+pub fn from_reader<R, T>(reader: R) -> Result<T>
+where
+    R: Read,
+    T: for<'de> Deserialize<'de>,
+{
+    let mut deserializer = Deserializer::new(reader);
+    T::deserialize(&mut deserializer)
+}
+
+/// Deserializes a `T` from `reader` using the Kafka wire format, like
+/// [`from_reader`], but capping `bytes`/`ByteBuf` fields' declared length at
+/// `max_len` instead of [`DEFAULT_MAX_LEN`].
+pub fn from_reader_with_max_len<R, T>(reader: R, max_len: usize) -> Result<T>
+where
+    R: Read,
+    T: for<'de> Deserialize<'de>,
+{
+    let mut deserializer = Deserializer::with_max_len(reader, max_len);
+    T::deserialize(&mut deserializer)
+}
+
+/// Deserializes a `T` from an in-memory byte slice using the Kafka wire
+/// format.
+pub fn from_bytes<T>(bytes: &[u8]) -> Result<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    from_reader(bytes)
+}
+
+/// Deserializes a `T` from an in-memory byte slice using the Kafka wire
+/// format, like [`from_bytes`], but capping `bytes`/`ByteBuf` fields'
+/// declared length at `max_len` instead of [`DEFAULT_MAX_LEN`].
+pub fn from_bytes_with_max_len<T>(bytes: &[u8], max_len: usize) -> Result<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    from_reader_with_max_len(bytes, max_len)
+}
+
+macro_rules! forward_to_deserialize_not_implemented {
+    ($($name:ident)*) => {
+        $(
+            fn $name<V>(self, _visitor: V) -> Result<V::Value>
+            where
+                V: Visitor<'de>,
+            {
+                Err(Error::Message(concat!(
+                    stringify!($name),
+                    " is not supported: the Kafka wire format is not self-describing"
+                ).into()))
+            }
+        )*
+    };
+}
+
+impl<'de, R: Read> de::Deserializer<'de> for &mut Deserializer<R> {
+    type Error = Error;
+
+    forward_to_deserialize_not_implemented! {
+        deserialize_any deserialize_ignored_any
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_bool(self.de.read_i8()? != 0)
+    }
+
+    fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i8(self.de.read_i8()?)
+    }
+
+    fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i16(self.de.read_i16()?)
+    }
+
+    fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i32(self.de.read_i32()?)
+    }
+
+    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i64(self.de.read_i64()?)
+    }
+
+    fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u8(self.de.read_i8()? as u8)
+    }
+
+    fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u16(self.de.read_i16()? as u16)
+    }
+
+    fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u32(self.de.read_i32()? as u32)
+    }
+
+    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_u64(self.de.read_i64()? as u64)
+    }
+
+    fn deserialize_f32<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::Message(
+            "the Kafka wire format has no floating-point primitive".into(),
+        ))
+    }
+
+    fn deserialize_f64<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::Message(
+            "the Kafka wire format has no floating-point primitive".into(),
+        ))
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let s = self.de.read_string()?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(Error::Message(
+                "expected a Kafka string containing exactly one character".into(),
+            )),
+        }
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.de.read_string()?)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.de.read_string()?)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_byte_buf(self.de.read_bytes(self.max_len)?)
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_byte_buf(self.de.read_bytes(self.max_len)?)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self.de.read_i8()? {
+            0 => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let len = self.de.read_i32()?;
+        if len < 0 {
+            return Err(Error::Message(
+                "invalid negative length for a non-nullable Kafka array".into(),
+            ));
+        }
+        visitor.visit_seq(BoundedSeqAccess {
+            de: self,
+            remaining: len as usize,
+        })
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(BoundedSeqAccess {
+            de: self,
+            remaining: len,
+        })
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(BoundedSeqAccess {
+            de: self,
+            remaining: len,
+        })
+    }
+
+    fn deserialize_map<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::Message(
+            "maps are not supported by the Kafka wire format".into(),
+        ))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(BoundedSeqAccess {
+            de: self,
+            remaining: fields.len(),
+        })
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_u64(visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_enum(EnumAccess { de: self })
+    }
+}
+
+/// Reads a fixed number of positional elements: used for Kafka arrays
+/// (whose count was already read from the `i32` length prefix) as well as
+/// tuples and structs (whose arity is known at compile time and carries no
+/// length prefix on the wire at all).
+struct BoundedSeqAccess<'a, R: Read> {
+    de: &'a mut Deserializer<R>,
+    remaining: usize,
+}
+
+impl<'a, 'de, R: Read> de::SeqAccess<'de> for BoundedSeqAccess<'a, R> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// Reads an enum's `i32` big-endian variant index, then dispatches to the
+/// matching `VariantAccess` method to read the variant's payload (if any).
+struct EnumAccess<'a, R: Read> {
+    de: &'a mut Deserializer<R>,
+}
+
+impl<'a, 'de, R: Read> de::EnumAccess<'de> for EnumAccess<'a, R> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant)>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant_index = self.de.de.read_i32()? as u32;
+        let value = seed.deserialize(U32Deserializer::<Error>::new(variant_index))?;
+        Ok((value, self))
+    }
+}
+
+impl<'a, 'de, R: Read> de::VariantAccess<'de> for EnumAccess<'a, R> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self.de)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_tuple(self.de, len, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_struct(self.de, "", fields, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! These tests round-trip plain serde-provided types through
+    //! [`crate::ser::to_bytes`] and [`from_bytes`] to exercise the
+    //! `Serializer`/`Deserializer` backend without requiring `serde_derive`.
+
+    use super::{from_bytes, from_bytes_with_max_len};
+    use crate::ser::to_bytes;
+    use serde::{Deserialize, Deserializer, Serialize};
+
+    /// Minimal stand-in for `serde_bytes::ByteBuf`: a `Vec<u8>` that routes
+    /// through `serialize_bytes`/`deserialize_byte_buf` instead of serde's
+    /// generic sequence handling, to exercise those two methods directly.
+    #[derive(Debug, PartialEq, Eq)]
+    struct RawBytes(Vec<u8>);
+
+    impl Serialize for RawBytes {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RawBytes {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_byte_buf(RawBytesVisitor).map(RawBytes)
+        }
+    }
+
+    struct RawBytesVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for RawBytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a byte buffer")
+        }
+
+        fn visit_byte_buf<E>(self, v: Vec<u8>) -> std::result::Result<Self::Value, E> {
+            Ok(v)
+        }
+    }
+
+    #[test]
+    fn test_byte_buf_roundtrip() {
+        let value = RawBytes(vec![1u8, 2, 3]);
+        let bytes = to_bytes(&value).expect("Failed to serialize ByteBuf");
+        let decoded: RawBytes = from_bytes(&bytes).expect("Failed to deserialize ByteBuf");
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_byte_buf_rejects_over_cap_length() {
+        // i32 length of 1000, which exceeds the max_len cap below.
+        let buffer = vec![0x00, 0x00, 0x03, 0xE8];
+        let result: Result<RawBytes, _> = from_bytes_with_max_len(&buffer, 16);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tuple_roundtrip() {
+        let value: (i32, String, Vec<i32>) = (7, "abc".to_string(), vec![1, 2, 3]);
+        let bytes = to_bytes(&value).expect("Failed to serialize tuple");
+        let decoded: (i32, String, Vec<i32>) =
+            from_bytes(&bytes).expect("Failed to deserialize tuple");
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_option_roundtrip() {
+        let present: Option<i16> = Some(9);
+        let bytes = to_bytes(&present).expect("Failed to serialize Some");
+        assert_eq!(
+            from_bytes::<Option<i16>>(&bytes).expect("Failed to deserialize Some"),
+            present
+        );
+
+        let absent: Option<i16> = None;
+        let bytes = to_bytes(&absent).expect("Failed to serialize None");
+        assert_eq!(
+            from_bytes::<Option<i16>>(&bytes).expect("Failed to deserialize None"),
+            absent
+        );
+    }
+
+    #[test]
+    fn test_nested_vec_roundtrip() {
+        let value: Vec<Vec<i32>> = vec![vec![1, 2], vec![], vec![3]];
+        let bytes = to_bytes(&value).expect("Failed to serialize nested Vec");
+        let decoded: Vec<Vec<i32>> =
+            from_bytes(&bytes).expect("Failed to deserialize nested Vec");
+        assert_eq!(decoded, value);
+    }
+}
@@ -0,0 +1,63 @@
+//! A shared error type for the [`crate::ser`] and [`crate::de`] Serde
+//! backends.
+//!
+//! The byte-level [`crate::io`] helpers return [`std::io::Error`] directly,
+//! but `serde::Serializer`/`serde::Deserializer` require an error type that
+//! also implements `serde::ser::Error`/`serde::de::Error` (to support
+//! `#[derive]`-generated code raising its own messages), which we cannot
+//! implement for a foreign type like `std::io::Error`. This type wraps it.
+
+use std::fmt;
+
+/// Errors produced while serializing to or deserializing from the Kafka
+/// wire format through the [`crate::ser::Serializer`] / [`crate::de::Deserializer`]
+/// Serde backend.
+#[derive(Debug)]
+pub enum Error {
+    /// An underlying I/O failure, or a malformed/oversized encoding
+    /// detected by one of the [`crate::io`] byte-level helpers.
+    Io(std::io::Error),
+    /// A Serde-level error, either raised by this crate (e.g. an
+    /// unsupported data-model shape) or by a type's own `Serialize`/
+    /// `Deserialize` implementation via `serde::ser::Error::custom`.
+    Message(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Message(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Message(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+/// Convenience alias used throughout [`crate::ser`] and [`crate::de`].
+pub type Result<T> = std::result::Result<T, Error>;
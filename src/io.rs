@@ -2,8 +2,144 @@
 //! raw bytes according to Kafka's wire protocol. It provides helper structs
 //! `KafkaSerializer` and `KafkaDeserializer` for use in your custom
 //! Serde implementations, or directly for low-level byte operations.
+//!
+//! These helpers are generic over [`KafkaWrite`]/[`KafkaRead`] rather than
+//! `std::io::Write`/`Read` directly, so they work in `no_std` (plus `alloc`)
+//! builds too: with the `std` feature enabled (the default) any
+//! `std::io::Write`/`Read` implementor works out of the box; without it,
+//! plain `&mut [u8]`/`&[u8]` slices are supported instead.
 
-use std::io::{self, Read, Write};
+use crate::{vec, String, Vec};
+
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+
+/// The error type produced by the byte-level [`KafkaRead`]/[`KafkaWrite`]
+/// operations in this module.
+///
+/// With the `std` feature enabled this is simply [`std::io::Error`]. Without
+/// it, there is no `std::io::Error` to reuse, so a minimal message-only
+/// error is used instead.
+#[cfg(feature = "std")]
+pub type IoError = std::io::Error;
+
+/// The error type produced by the byte-level [`KafkaRead`]/[`KafkaWrite`]
+/// operations in this module.
+///
+/// With the `std` feature enabled this is simply [`std::io::Error`]. Without
+/// it, there is no `std::io::Error` to reuse, so a minimal message-only
+/// error is used instead.
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub struct IoError(String);
+
+#[cfg(not(feature = "std"))]
+impl core::fmt::Display for IoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The `Result` type returned by the byte-level [`KafkaRead`]/[`KafkaWrite`]
+/// operations in this module.
+#[cfg(feature = "std")]
+pub type IoResult<T> = std::io::Result<T>;
+
+/// The `Result` type returned by the byte-level [`KafkaRead`]/[`KafkaWrite`]
+/// operations in this module.
+#[cfg(not(feature = "std"))]
+pub type IoResult<T> = Result<T, IoError>;
+
+/// Builds an `IoError` signaling malformed or out-of-bounds data (negative
+/// lengths, invalid UTF-8, limit overruns, truncated input).
+#[cfg(feature = "std")]
+pub(crate) fn invalid_data(msg: &str) -> IoError {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, msg.to_string())
+}
+
+/// Builds an `IoError` signaling malformed or out-of-bounds data (negative
+/// lengths, invalid UTF-8, limit overruns, truncated input).
+#[cfg(not(feature = "std"))]
+pub(crate) fn invalid_data(msg: &str) -> IoError {
+    IoError(String::from(msg))
+}
+
+/// Checks that `len` fits in an `i16`, returning an `IoError` instead of
+/// letting a plain `len as i16` cast silently truncate/wrap. Shared by every
+/// writer whose Kafka wire length prefix is `i16`-width.
+fn checked_len_i16(len: usize) -> IoResult<i16> {
+    i16::try_from(len).map_err(|_| invalid_data("length exceeds i16::MAX"))
+}
+
+/// Checks that `len` fits in an `i32`, returning an `IoError` instead of
+/// letting a plain `len as i32` cast silently truncate/wrap. Shared by every
+/// writer whose Kafka wire length prefix is `i32`-width.
+fn checked_len_i32(len: usize) -> IoResult<i32> {
+    i32::try_from(len).map_err(|_| invalid_data("length exceeds i32::MAX"))
+}
+
+/// A minimal stand-in for `std::io::Write`, so the primitives in this module
+/// can target a byte sink without depending on `std`.
+///
+/// Blanket-implemented for every `std::io::Write` type when the `std`
+/// feature is enabled (the default); implemented directly for `&mut [u8]`
+/// otherwise.
+pub trait KafkaWrite {
+    /// Writes all of `buf`, returning an error if it cannot all be written.
+    fn write_all(&mut self, buf: &[u8]) -> IoResult<()>;
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> KafkaWrite for W {
+    fn write_all(&mut self, buf: &[u8]) -> IoResult<()> {
+        Write::write_all(self, buf)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl KafkaWrite for &mut [u8] {
+    fn write_all(&mut self, buf: &[u8]) -> IoResult<()> {
+        if buf.len() > self.len() {
+            return Err(invalid_data("not enough space remaining in buffer"));
+        }
+        let (head, tail) = core::mem::take(self).split_at_mut(buf.len());
+        head.copy_from_slice(buf);
+        *self = tail;
+        Ok(())
+    }
+}
+
+/// A minimal stand-in for `std::io::Read`, so the primitives in this module
+/// can source bytes without depending on `std`.
+///
+/// Blanket-implemented for every `std::io::Read` type when the `std`
+/// feature is enabled (the default); implemented directly for `&[u8]`
+/// otherwise.
+pub trait KafkaRead {
+    /// Reads exactly `buf.len()` bytes, returning an error if the source is
+    /// exhausted first.
+    fn read_exact(&mut self, buf: &mut [u8]) -> IoResult<()>;
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> KafkaRead for R {
+    fn read_exact(&mut self, buf: &mut [u8]) -> IoResult<()> {
+        Read::read_exact(self, buf)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl KafkaRead for &[u8] {
+    fn read_exact(&mut self, buf: &mut [u8]) -> IoResult<()> {
+        if buf.len() > self.len() {
+            return Err(invalid_data("unexpected end of Kafka byte slice"));
+        }
+        let (head, tail) = self.split_at(buf.len());
+        buf.copy_from_slice(head);
+        *self = tail;
+        Ok(())
+    }
+}
 
 /// A serializer for writing Kafka wire data to an underlying `Write` stream.
 ///
@@ -26,37 +162,179 @@ use std::io::{self, Read, Write};
 /// // i32 42 in big-endian is [0, 0, 0, 42].
 /// assert_eq!(buffer, vec![0x00, 0x00, 0x00, 0x2A]);
 /// ```
-pub struct KafkaSerializer<W: Write> {
+pub struct KafkaSerializer<W: KafkaWrite> {
     writer: W,
 }
 
-impl<W: Write> KafkaSerializer<W> {
-    /// Create a new `KafkaSerializer` that writes to the given `Write` implementor.
+impl<W: KafkaWrite> KafkaSerializer<W> {
+    /// Create a new `KafkaSerializer` that writes to the given `KafkaWrite` implementor.
     pub fn new(writer: W) -> Self {
         KafkaSerializer { writer }
     }
 
     /// Writes a `i32` in **big-endian** format to the underlying stream.
-    pub fn write_i32(&mut self, val: i32) -> io::Result<()> {
+    pub fn write_i32(&mut self, val: i32) -> IoResult<()> {
         self.writer.write_all(&val.to_be_bytes())
     }
 
     /// Writes a `i16` in **big-endian** format to the underlying stream.
-    pub fn write_i16(&mut self, val: i16) -> io::Result<()> {
+    pub fn write_i16(&mut self, val: i16) -> IoResult<()> {
         self.writer.write_all(&val.to_be_bytes())
     }
 
     /// Writes an `i64` in **big-endian** format to the underlying stream.
-    pub fn write_i64(&mut self, val: i64) -> io::Result<()> {
+    pub fn write_i64(&mut self, val: i64) -> IoResult<()> {
         self.writer.write_all(&val.to_be_bytes())
     }
 
     /// Writes an `i8` (which is just one byte).
-    pub fn write_i8(&mut self, val: i8) -> io::Result<()> {
+    pub fn write_i8(&mut self, val: i8) -> IoResult<()> {
         self.writer.write_all(&[val as u8])
     }
 
-    // TODO: Add more specialized write methods (varint, varlong, strings, arrays) as needed.
+    /// Writes an `i32` as a Kafka **zigzag-encoded varint**.
+    ///
+    /// The value is first zigzag-mapped to an unsigned integer so that small
+    /// magnitudes (positive or negative) stay small, then emitted 7 bits per
+    /// byte, little-endian, with the high bit (`0x80`) set on every byte
+    /// except the last.
+    pub fn write_varint(&mut self, val: i32) -> IoResult<()> {
+        let zigzagged = ((val << 1) ^ (val >> 31)) as u32;
+        self.write_uvarint_bytes(zigzagged as u64)
+    }
+
+    /// Writes an `i64` as a Kafka **zigzag-encoded varlong**.
+    ///
+    /// Encoding follows the same scheme as [`write_varint`][Self::write_varint],
+    /// but zigzag-maps a 64-bit value instead of a 32-bit one.
+    pub fn write_varlong(&mut self, val: i64) -> IoResult<()> {
+        let zigzagged = ((val << 1) ^ (val >> 63)) as u64;
+        self.write_uvarint_bytes(zigzagged)
+    }
+
+    /// Writes a `u32` as a Kafka **unsigned varint**: 7 bits per byte,
+    /// little-endian, with the high bit (`0x80`) set on every byte except
+    /// the last. Unlike [`write_varint`][Self::write_varint], the value is
+    /// encoded as-is, with no zigzag mapping, matching Kafka's
+    /// `UNSIGNED_VARINT` type (used e.g. for compact-collection lengths and
+    /// tagged-field tags/lengths).
+    pub fn write_uvarint(&mut self, val: u32) -> IoResult<()> {
+        self.write_uvarint_bytes(val as u64)
+    }
+
+    /// Writes `val` verbatim, with no length prefix of any kind. Building
+    /// block for primitives (like tagged-field values) whose length was
+    /// already written separately.
+    pub fn write_raw_bytes(&mut self, val: &[u8]) -> IoResult<()> {
+        self.writer.write_all(val)
+    }
+
+    /// Shared 7-bits-per-byte encoder: used by `write_varint` and
+    /// `write_varlong` once the value has been zigzag-mapped to unsigned, and
+    /// by `write_uvarint` and the compact string/array writers with the
+    /// value as-is.
+    fn write_uvarint_bytes(&mut self, mut val: u64) -> IoResult<()> {
+        loop {
+            let byte = (val & 0x7F) as u8;
+            val >>= 7;
+            if val != 0 {
+                self.writer.write_all(&[byte | 0x80])?;
+            } else {
+                self.writer.write_all(&[byte])?;
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a Kafka **string**: an `i16` big-endian length followed by the
+    /// UTF-8 bytes of `val`.
+    ///
+    /// Returns an error if `val` is longer than `i16::MAX` bytes, which is
+    /// the largest length Kafka's non-compact `STRING` type can represent.
+    pub fn write_string(&mut self, val: &str) -> IoResult<()> {
+        let bytes = val.as_bytes();
+        self.write_i16(checked_len_i16(bytes.len())?)?;
+        self.writer.write_all(bytes)
+    }
+
+    /// Writes a Kafka **nullable string**: like [`write_string`][Self::write_string],
+    /// but `None` is encoded as length `-1` with no following bytes.
+    pub fn write_nullable_string(&mut self, val: Option<&str>) -> IoResult<()> {
+        match val {
+            None => self.write_i16(-1),
+            Some(s) => self.write_string(s),
+        }
+    }
+
+    /// Writes a Kafka **compact string** (flexible versions): an unsigned
+    /// varint equal to `length + 1` (where `0` means null) followed by the
+    /// UTF-8 bytes.
+    pub fn write_compact_string(&mut self, val: Option<&str>) -> IoResult<()> {
+        match val {
+            None => self.write_uvarint_bytes(0),
+            Some(s) => {
+                let bytes = s.as_bytes();
+                self.write_uvarint_bytes(bytes.len() as u64 + 1)?;
+                self.writer.write_all(bytes)
+            }
+        }
+    }
+
+    /// Writes a Kafka **bytes** value: an `i32` big-endian length followed
+    /// by the raw bytes of `val`.
+    ///
+    /// Returns an error if `val` is longer than `i32::MAX` bytes, which is
+    /// the largest length Kafka's non-compact `BYTES` type can represent.
+    pub fn write_bytes(&mut self, val: &[u8]) -> IoResult<()> {
+        self.write_i32(checked_len_i32(val.len())?)?;
+        self.writer.write_all(val)
+    }
+
+    /// Writes a Kafka **array**: an `i32` big-endian element count (`-1`
+    /// meaning null), followed by each element written in turn via
+    /// `write_elem`.
+    ///
+    /// Returns an error if `items` has more than `i32::MAX` elements, which
+    /// is the largest count Kafka's non-compact `ARRAY` type can represent.
+    pub fn write_array<T>(
+        &mut self,
+        items: Option<&[T]>,
+        mut write_elem: impl FnMut(&mut Self, &T) -> IoResult<()>,
+    ) -> IoResult<()> {
+        match items {
+            None => self.write_i32(-1),
+            Some(elems) => {
+                self.write_i32(checked_len_i32(elems.len())?)?;
+                for elem in elems {
+                    write_elem(self, elem)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Writes a Kafka **compact array** (flexible versions): an unsigned
+    /// varint equal to `len + 1` (where `0` means null), followed by each
+    /// element written in turn via `write_elem`.
+    pub fn write_compact_array<T>(
+        &mut self,
+        items: Option<&[T]>,
+        mut write_elem: impl FnMut(&mut Self, &T) -> IoResult<()>,
+    ) -> IoResult<()> {
+        match items {
+            None => self.write_uvarint_bytes(0),
+            Some(elems) => {
+                self.write_uvarint_bytes(elems.len() as u64 + 1)?;
+                for elem in elems {
+                    write_elem(self, elem)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    // TODO: Add more specialized write methods as needed.
 }
 
 /// A deserializer for reading Kafka wire data from an underlying `Read` stream.
@@ -74,45 +352,315 @@ impl<W: Write> KafkaSerializer<W> {
 /// let value = de.read_i32().unwrap();
 /// assert_eq!(value, 42);
 /// ```
-pub struct KafkaDeserializer<R: Read> {
+pub struct KafkaDeserializer<R: KafkaRead> {
     reader: R,
+    /// Remaining number of bytes this deserializer is allowed to consume,
+    /// or `None` for no limit. Set via [`with_limit`][Self::with_limit].
+    limit: Option<u64>,
 }
 
-impl<R: Read> KafkaDeserializer<R> {
-    /// Create a new `KafkaDeserializer` that reads from the given `Read` implementor.
+impl<R: KafkaRead> KafkaDeserializer<R> {
+    /// Create a new `KafkaDeserializer` that reads from the given `KafkaRead` implementor.
     pub fn new(reader: R) -> Self {
-        KafkaDeserializer { reader }
+        KafkaDeserializer {
+            reader,
+            limit: None,
+        }
+    }
+
+    /// Create a new `KafkaDeserializer` that aborts with an error once more
+    /// than `max_bytes` total have been consumed from `reader`.
+    ///
+    /// Mirrors `bincode`'s `Bounded` read limit: Kafka frames arrive with an
+    /// attacker-influenced size prefix, and a single malformed length field
+    /// fed into a string/array reader could otherwise drive an enormous
+    /// allocation or read before the caller ever sees an error. The limit
+    /// decrements as each `read_*` method consumes bytes and composes with
+    /// the length-prefixed collection readers, so it bounds the *total*
+    /// bytes read across an entire nested decode, not just a single field.
+    pub fn with_limit(reader: R, max_bytes: u64) -> Self {
+        KafkaDeserializer {
+            reader,
+            limit: Some(max_bytes),
+        }
+    }
+
+    /// Reads exactly `buf.len()` bytes, first checking that doing so would
+    /// not exceed the configured [`with_limit`][Self::with_limit] budget.
+    fn read_exact(&mut self, buf: &mut [u8]) -> IoResult<()> {
+        if let Some(limit) = self.limit {
+            if buf.len() as u64 > limit {
+                return Err(invalid_data("Kafka deserializer read limit exceeded"));
+            }
+        }
+        self.reader.read_exact(buf)?;
+        if let Some(limit) = self.limit.as_mut() {
+            *limit -= buf.len() as u64;
+        }
+        Ok(())
     }
 
     /// Reads a `i32` in **big-endian** format from the underlying stream.
-    pub fn read_i32(&mut self) -> io::Result<i32> {
+    pub fn read_i32(&mut self) -> IoResult<i32> {
         let mut buf = [0u8; 4];
-        self.reader.read_exact(&mut buf)?;
+        self.read_exact(&mut buf)?;
         Ok(i32::from_be_bytes(buf))
     }
 
     /// Reads a `i16` in **big-endian** format from the underlying stream.
-    pub fn read_i16(&mut self) -> io::Result<i16> {
+    pub fn read_i16(&mut self) -> IoResult<i16> {
         let mut buf = [0u8; 2];
-        self.reader.read_exact(&mut buf)?;
+        self.read_exact(&mut buf)?;
         Ok(i16::from_be_bytes(buf))
     }
 
     /// Reads an `i64` in **big-endian** format from the underlying stream.
-    pub fn read_i64(&mut self) -> io::Result<i64> {
+    pub fn read_i64(&mut self) -> IoResult<i64> {
         let mut buf = [0u8; 8];
-        self.reader.read_exact(&mut buf)?;
+        self.read_exact(&mut buf)?;
         Ok(i64::from_be_bytes(buf))
     }
 
     /// Reads an `i8`, which is just a single byte interpreted as `i8`.
-    pub fn read_i8(&mut self) -> io::Result<i8> {
+    pub fn read_i8(&mut self) -> IoResult<i8> {
         let mut buf = [0u8; 1];
-        self.reader.read_exact(&mut buf)?;
+        self.read_exact(&mut buf)?;
         Ok(buf[0] as i8)
     }
 
-    // TODO: Add specialized read methods (varint, varlong, strings, arrays) as needed.
+    /// Reads a Kafka **zigzag-encoded varint** into an `i32`.
+    ///
+    /// Returns an error if more than 5 bytes are consumed without
+    /// terminating, if the decoded value does not fit in a 32-bit value
+    /// (which would otherwise silently truncate), or if the stream ends
+    /// prematurely.
+    pub fn read_varint(&mut self) -> IoResult<i32> {
+        let raw = self.read_uvarint_bytes(5)?;
+        let val = u32::try_from(raw)
+            .map_err(|_| invalid_data("varint overflowed a 32-bit value"))?;
+        Ok(((val >> 1) as i32) ^ -((val & 1) as i32))
+    }
+
+    /// Reads a Kafka **zigzag-encoded varlong** into an `i64`.
+    ///
+    /// Returns an error if more than 10 bytes are consumed without
+    /// terminating, if the decoded value does not fit in a 64-bit value
+    /// (which would otherwise silently truncate), or if the stream ends
+    /// prematurely.
+    pub fn read_varlong(&mut self) -> IoResult<i64> {
+        let raw = self.read_uvarint_bytes(10)?;
+        Ok(((raw >> 1) as i64) ^ -((raw & 1) as i64))
+    }
+
+    /// Reads a Kafka **unsigned varint** into a `u32`. Unlike
+    /// [`read_varint`][Self::read_varint], the value is decoded as-is, with
+    /// no zigzag unmapping, matching Kafka's `UNSIGNED_VARINT` type (used
+    /// e.g. for compact-collection lengths and tagged-field tags/lengths).
+    ///
+    /// Returns an error if more than 5 bytes are consumed without
+    /// terminating (which would overflow a 32-bit value), if the decoded
+    /// value itself does not fit in a `u32`, or if the stream ends
+    /// prematurely.
+    pub fn read_uvarint(&mut self) -> IoResult<u32> {
+        let raw = self.read_uvarint_bytes(5)?;
+        u32::try_from(raw).map_err(|_| invalid_data("unsigned varint overflowed a 32-bit value"))
+    }
+
+    /// Reads exactly `len` raw bytes, with no length prefix of any kind.
+    ///
+    /// `max_len` bounds `len` so a corrupt or hostile length cannot trigger
+    /// an unbounded allocation.
+    pub fn read_raw_bytes(&mut self, len: usize, max_len: usize) -> IoResult<Vec<u8>> {
+        if len > max_len {
+            return Err(invalid_data("raw byte length exceeds configured maximum"));
+        }
+        let mut buf = vec![0u8; len];
+        self.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Shared 7-bits-per-byte decoder used by `read_varint`, `read_varlong`,
+    /// `read_uvarint` and the compact string/array readers. Accumulates 7-bit groups, shifting left by `7 *
+    /// position`, until a byte without the continuation bit is seen.
+    ///
+    /// Since the accumulator is a `u64`, a 10-byte encoding (the max for
+    /// `read_varlong`) can carry a final 7-bit group whose high bits would
+    /// land past bit 63 — plain `<<` silently drops those bits rather than
+    /// erroring, so each group is checked against the bits actually
+    /// available before being folded in.
+    fn read_uvarint_bytes(&mut self, max_bytes: usize) -> IoResult<u64> {
+        let mut result: u64 = 0;
+        for i in 0..max_bytes {
+            let mut buf = [0u8; 1];
+            self.read_exact(&mut buf)?;
+            let byte = buf[0];
+            let low7 = (byte & 0x7F) as u64;
+            let shift = 7 * i;
+            if shift >= 64 {
+                if low7 != 0 {
+                    return Err(invalid_data("varint value overflowed 64 bits"));
+                }
+            } else {
+                let avail_bits = 64 - shift;
+                if avail_bits < 7 && (low7 >> avail_bits) != 0 {
+                    return Err(invalid_data("varint value overflowed 64 bits"));
+                }
+                result |= low7 << shift;
+            }
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+        Err(invalid_data("varint exceeded maximum encoded length"))
+    }
+
+    /// Reads a Kafka **string**: an `i16` big-endian length followed by that
+    /// many UTF-8 bytes.
+    ///
+    /// Returns an error if the length is negative or the bytes are not
+    /// valid UTF-8.
+    pub fn read_string(&mut self) -> IoResult<String> {
+        let len = self.read_i16()?;
+        if len < 0 {
+            return Err(invalid_data(
+                "negative length for non-nullable Kafka string",
+            ));
+        }
+        let mut buf = vec![0u8; len as usize];
+        self.read_exact(&mut buf)?;
+        String::from_utf8(buf).map_err(|_| invalid_data("invalid UTF-8 in Kafka string"))
+    }
+
+    /// Reads a Kafka **nullable string**: like [`read_string`][Self::read_string],
+    /// but length `-1` decodes to `None`.
+    ///
+    /// Returns an error if the length is negative but not the `-1` sentinel,
+    /// or if the bytes are not valid UTF-8.
+    pub fn read_nullable_string(&mut self) -> IoResult<Option<String>> {
+        let len = self.read_i16()?;
+        if len == -1 {
+            return Ok(None);
+        }
+        if len < 0 {
+            return Err(invalid_data(
+                "invalid negative length for nullable Kafka string",
+            ));
+        }
+        let mut buf = vec![0u8; len as usize];
+        self.read_exact(&mut buf)?;
+        String::from_utf8(buf)
+            .map(Some)
+            .map_err(|_| invalid_data("invalid UTF-8 in Kafka string"))
+    }
+
+    /// Reads a Kafka **bytes** value: an `i32` big-endian length followed by
+    /// that many raw bytes.
+    ///
+    /// `max_len` bounds the declared length so a corrupt or hostile length
+    /// field cannot trigger an unbounded allocation.
+    pub fn read_bytes(&mut self, max_len: usize) -> IoResult<Vec<u8>> {
+        let len = self.read_i32()?;
+        if len < 0 {
+            return Err(invalid_data("negative length for non-nullable Kafka bytes"));
+        }
+        if len as usize > max_len {
+            return Err(invalid_data(
+                "Kafka bytes length exceeds configured maximum",
+            ));
+        }
+        let mut buf = vec![0u8; len as usize];
+        self.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Reads a Kafka **compact string** (flexible versions): an unsigned
+    /// varint equal to `length + 1` (where `0` means null) followed by that
+    /// many UTF-8 bytes.
+    ///
+    /// `max_len` bounds the declared length so a corrupt or hostile length
+    /// field cannot trigger an unbounded allocation; a count exceeding it
+    /// is rejected before any bytes are read.
+    ///
+    /// Returns an error if the bytes are not valid UTF-8.
+    pub fn read_compact_string(&mut self, max_len: usize) -> IoResult<Option<String>> {
+        let raw = self.read_uvarint_bytes(5)?;
+        if raw == 0 {
+            return Ok(None);
+        }
+        let len = raw - 1;
+        if len as usize > max_len {
+            return Err(invalid_data(
+                "Kafka compact string length exceeds configured maximum",
+            ));
+        }
+        let mut buf = vec![0u8; len as usize];
+        self.read_exact(&mut buf)?;
+        String::from_utf8(buf)
+            .map(Some)
+            .map_err(|_| invalid_data("invalid UTF-8 in Kafka string"))
+    }
+
+    /// Reads a Kafka **array**: an `i32` big-endian element count (`-1`
+    /// meaning null), followed by that many elements read in turn via
+    /// `read_elem`.
+    ///
+    /// `max_elements` bounds the declared count so a corrupt or hostile
+    /// length field cannot trigger an unbounded allocation; a count
+    /// exceeding it is rejected before any elements are read.
+    pub fn read_array<T>(
+        &mut self,
+        max_elements: usize,
+        mut read_elem: impl FnMut(&mut Self) -> IoResult<T>,
+    ) -> IoResult<Option<Vec<T>>> {
+        let len = self.read_i32()?;
+        if len == -1 {
+            return Ok(None);
+        }
+        if len < 0 {
+            return Err(invalid_data("invalid negative length for Kafka array"));
+        }
+        if len as usize > max_elements {
+            return Err(invalid_data(
+                "Kafka array length exceeds configured maximum element cap",
+            ));
+        }
+        let mut elems = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            elems.push(read_elem(self)?);
+        }
+        Ok(Some(elems))
+    }
+
+    /// Reads a Kafka **compact array** (flexible versions): an unsigned
+    /// varint equal to `len + 1` (where `0` means null), followed by that
+    /// many elements read in turn via `read_elem`.
+    ///
+    /// `max_elements` bounds the declared count so a corrupt or hostile
+    /// length field cannot trigger an unbounded allocation; a count
+    /// exceeding it is rejected before any elements are read.
+    pub fn read_compact_array<T>(
+        &mut self,
+        max_elements: usize,
+        mut read_elem: impl FnMut(&mut Self) -> IoResult<T>,
+    ) -> IoResult<Option<Vec<T>>> {
+        let raw = self.read_uvarint_bytes(5)?;
+        if raw == 0 {
+            return Ok(None);
+        }
+        let len = raw - 1;
+        if len as usize > max_elements {
+            return Err(invalid_data(
+                "Kafka compact array length exceeds configured maximum element cap",
+            ));
+        }
+        let mut elems = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            elems.push(read_elem(self)?);
+        }
+        Ok(Some(elems))
+    }
+
+    // TODO: Add specialized read methods as needed.
 }
 
 #[cfg(test)]
@@ -155,4 +703,105 @@ mod tests {
             assert_eq!(val, -5);
         }
     }
+
+    #[test]
+    fn test_with_limit_allows_reads_within_budget() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_i32(42).unwrap();
+        }
+
+        let mut de = KafkaDeserializer::with_limit(Cursor::new(&buffer), 4);
+        assert_eq!(de.read_i32().unwrap(), 42);
+    }
+
+    #[test]
+    fn test_with_limit_rejects_reads_over_budget() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_i32(42).unwrap();
+        }
+
+        let mut de = KafkaDeserializer::with_limit(Cursor::new(&buffer), 3);
+        assert!(de.read_i32().is_err());
+    }
+
+    #[test]
+    fn test_write_string_rejects_length_over_i16_max() {
+        let val = "a".repeat(i16::MAX as usize + 1);
+        let mut buffer = Vec::new();
+        let mut ser = KafkaSerializer::new(&mut buffer);
+        assert!(ser.write_string(&val).is_err());
+    }
+
+    #[test]
+    fn test_with_limit_is_shared_across_nested_reads() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_string("hello").unwrap(); // 2-byte length + 5 bytes
+            ser.write_i8(1).unwrap();
+        }
+
+        // Exactly enough for the string, none left over for the trailing i8.
+        let mut de = KafkaDeserializer::with_limit(Cursor::new(&buffer), 7);
+        assert_eq!(de.read_string().unwrap(), "hello");
+        assert!(de.read_i8().is_err());
+    }
+
+    #[test]
+    fn test_uvarint_roundtrip() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_uvarint(0).unwrap();
+            ser.write_uvarint(127).unwrap();
+            ser.write_uvarint(128).unwrap();
+            ser.write_uvarint(u32::MAX).unwrap();
+        }
+
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert_eq!(de.read_uvarint().unwrap(), 0);
+        assert_eq!(de.read_uvarint().unwrap(), 127);
+        assert_eq!(de.read_uvarint().unwrap(), 128);
+        assert_eq!(de.read_uvarint().unwrap(), u32::MAX);
+    }
+
+    #[test]
+    fn test_uvarint_differs_from_zigzag_varint_encoding() {
+        // -1 zigzags to 1 (a single 0x01 byte), whereas the unsigned varint
+        // encoding of the same bit pattern as a u32 (u32::MAX) takes 5
+        // bytes: the two encoders are not interchangeable.
+        let mut varint_buf = Vec::new();
+        KafkaSerializer::new(&mut varint_buf)
+            .write_varint(-1)
+            .unwrap();
+        assert_eq!(varint_buf, vec![0x01]);
+
+        let mut uvarint_buf = Vec::new();
+        KafkaSerializer::new(&mut uvarint_buf)
+            .write_uvarint(u32::MAX)
+            .unwrap();
+        assert_eq!(uvarint_buf.len(), 5);
+    }
+
+    #[test]
+    fn test_raw_bytes_roundtrip() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_raw_bytes(&[1, 2, 3]).unwrap();
+        }
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert_eq!(de.read_raw_bytes(3, 16).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_raw_bytes_rejects_over_cap_length() {
+        let buffer = vec![1, 2, 3];
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert!(de.read_raw_bytes(3, 2).is_err());
+    }
 }
@@ -1,17 +1,67 @@
 //! Top-level docs about `serde_kafka_primitives`
 //! This library implements Serde-based logic for Kafka wire protocol primitives.
+//!
+//! This crate is `no_std` (plus `alloc`) compatible: the low-level byte
+//! primitives in [`io`] work against any [`io::KafkaRead`]/[`io::KafkaWrite`]
+//! implementor, including plain `&[u8]`/`&mut [u8]` slices, with no heap
+//! allocation beyond the `String`/`Vec` buffers the primitives themselves
+//! decode into. Disable the default `std` feature to build in that mode.
+//! The [`ser`]/[`de`] `serde::Serializer`/`serde::Deserializer` backend and
+//! [`error::Error`] currently require `std` (to implement
+//! `std::error::Error`) and are gated behind the `std` feature.
+//!
+//! # `#[derive(Serialize, Deserialize)]` does not support nullable fields
+//! The [`ser`]/[`de`] backend encodes every `Option<T>` field (including
+//! ones typed as [`KafkaNullableString`]/[`KafkaCompactString`]/
+//! [`KafkaArray`]/[`KafkaCompactArray`]) with a generic presence byte, not
+//! Kafka's native `-1`/`0` nullable sentinels — see the [`ser`] module docs
+//! for the full explanation. A `#[derive]`'d struct with a nullable field
+//! will round-trip correctly against itself, but will NOT produce or
+//! accept real Kafka wire bytes for that field. For protocol-accurate
+//! nullable encoding, read/write that field directly with
+//! [`KafkaSerializer`]/[`KafkaDeserializer`]'s `write_*`/`read_*` methods
+//! instead of deriving.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub(crate) use std::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+#[cfg(not(feature = "std"))]
+pub(crate) use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
 
 mod int;
-// mod varint;
-// mod string;
-// mod array;
+mod varint;
+mod string;
+mod array;
+mod tagged;
+#[cfg(feature = "std")]
+pub mod de;
+#[cfg(feature = "std")]
+pub mod error;
 pub mod io;
+#[cfg(feature = "std")]
+pub mod ser;
 
 // Expose these modules/types publicly so users can import them directly.
 pub use int::{KafkaInt16, KafkaInt32, KafkaInt64, KafkaInt8};
-// pub use varint::{KafkaVarInt, KafkaVarLong};
-// pub use string::{KafkaString, KafkaNullableString, KafkaCompactString};
-// pub use array::{KafkaArray, KafkaCompactArray};
+pub use varint::{KafkaVarInt, KafkaVarLong};
+pub use string::{KafkaCompactString, KafkaNullableString, KafkaString};
+pub use array::{KafkaArray, KafkaCompactArray};
+pub use tagged::TaggedFields;
 
 // If you create custom serializer/deserializer structs:
 pub use io::{KafkaDeserializer, KafkaSerializer};
+
+// The full `serde::Serializer`/`serde::Deserializer` backend, for deriving
+// `Serialize`/`Deserialize` directly on request/response structs. Requires
+// the `std` feature; see the crate-level docs above.
+#[cfg(feature = "std")]
+pub use de::{from_bytes, from_bytes_with_max_len, from_reader, from_reader_with_max_len};
+#[cfg(feature = "std")]
+pub use error::Error;
+#[cfg(feature = "std")]
+pub use ser::{to_bytes, to_writer};
@@ -0,0 +1,390 @@
+//! A `serde::Serializer` backend that encodes values directly to Kafka's
+//! wire format, backed by [`KafkaSerializer`].
+//!
+//! Mirrors the `ser` module structure used by crates like `bincode`: a
+//! [`Serializer`] type plus top-level [`to_writer`]/[`to_bytes`] entry
+//! points, so a request/response struct can simply `#[derive(Serialize)]`
+//! and get correct big-endian integers, length-prefixed strings, and
+//! arrays for free.
+//!
+//! **Does not apply to nullable fields** — see [Limitations](#limitations)
+//! below: a `#[derive(Serialize)]`'d struct with an `Option<T>` field
+//! (including one typed as `KafkaNullableString`/`KafkaCompactString`/
+//! `KafkaArray`/`KafkaCompactArray`) will NOT produce Kafka's native
+//! nullable encoding through this module. Use [`KafkaSerializer`]'s
+//! `write_*` methods directly for any struct with a nullable field.
+//!
+//! # Limitations
+//! The Kafka wire format is not self-describing and has no single
+//! "optional value" convention: `NULLABLE_STRING` uses an `i16` `-1`
+//! sentinel, `NULLABLE_BYTES`/arrays use an `i32` `-1` sentinel, and compact
+//! (flexible-versions) variants use an unsigned varint `0` sentinel. Serde's
+//! generic `Option<T>` gives `serialize_none` no way to know which of these
+//! the caller meant, so plain `Option<T>` fields (including the
+//! [`crate::KafkaNullableString`] / [`crate::KafkaCompactString`] /
+//! [`crate::KafkaArray`] / [`crate::KafkaCompactArray`] wrapper types, which
+//! forward to the generic `Option`/`Vec` impls) are instead encoded with a
+//! presence byte (`0` = `None`, `1` = `Some(value)`) ahead of the value, as
+//! `bincode` does. For protocol-accurate nullable encodings, bypass
+//! [`Serializer`] entirely and use [`KafkaSerializer`]'s
+//! `write_nullable_string`/`write_compact_string`/`write_array`/
+//! `write_compact_array` methods directly.
+
+use crate::error::{Error, Result};
+use crate::io::KafkaSerializer;
+use serde::{ser, Serialize};
+use std::io::Write;
+
+/// A `serde::Serializer` that writes values to an underlying `Write` stream
+/// using the Kafka wire format.
+pub struct Serializer<W: Write> {
+    ser: KafkaSerializer<W>,
+}
+
+impl<W: Write> Serializer<W> {
+    /// Create a new `Serializer` that writes to the given `Write` implementor.
+    pub fn new(writer: W) -> Self {
+        Serializer {
+            ser: KafkaSerializer::new(writer),
+        }
+    }
+}
+
+/// Serializes `value` to `writer` using the Kafka wire format.
+pub fn to_writer<W, T>(writer: W, value: &T) -> Result<()>
+where
+    W: Write,
+    T: Serialize + ?Sized,
+{
+    let mut serializer = Serializer::new(writer);
+    value.serialize(&mut serializer)
+}
+
+/// Serializes `value` into a freshly allocated `Vec<u8>` using the Kafka
+/// wire format.
+pub fn to_bytes<T>(value: &T) -> Result<Vec<u8>>
+where
+    T: Serialize + ?Sized,
+{
+    let mut buf = Vec::new();
+    to_writer(&mut buf, value)?;
+    Ok(buf)
+}
+
+impl<'a, W: Write> ser::Serializer for &'a mut Serializer<W> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = SeqSerializer<'a, W>;
+    type SerializeTuple = SeqSerializer<'a, W>;
+    type SerializeTupleStruct = SeqSerializer<'a, W>;
+    type SerializeTupleVariant = SeqSerializer<'a, W>;
+    type SerializeMap = StructSerializer<'a, W>;
+    type SerializeStruct = StructSerializer<'a, W>;
+    type SerializeStructVariant = StructSerializer<'a, W>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        Ok(self.ser.write_i8(v as i8)?)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        Ok(self.ser.write_i8(v)?)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        Ok(self.ser.write_i16(v)?)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        Ok(self.ser.write_i32(v)?)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        Ok(self.ser.write_i64(v)?)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        Ok(self.ser.write_i8(v as i8)?)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        Ok(self.ser.write_i16(v as i16)?)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        Ok(self.ser.write_i32(v as i32)?)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        Ok(self.ser.write_i64(v as i64)?)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<()> {
+        Err(Error::Message(
+            "the Kafka wire format has no floating-point primitive".into(),
+        ))
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<()> {
+        Err(Error::Message(
+            "the Kafka wire format has no floating-point primitive".into(),
+        ))
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.serialize_str(v.encode_utf8(&mut [0u8; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        Ok(self.ser.write_string(v)?)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        Ok(self.ser.write_bytes(v)?)
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        Ok(self.ser.write_i8(0)?)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.ser.write_i8(1)?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        Ok(self.ser.write_i32(variant_index as i32)?)
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.ser.write_i32(variant_index as i32)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        let len = len.ok_or_else(|| {
+            Error::Message("Kafka arrays require a known length up front".into())
+        })?;
+        self.ser.write_i32(len as i32)?;
+        Ok(SeqSerializer { ser: self })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Ok(SeqSerializer { ser: self })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Ok(SeqSerializer { ser: self })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        self.ser.write_i32(variant_index as i32)?;
+        Ok(SeqSerializer { ser: self })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(Error::Message(
+            "maps are not supported by the Kafka wire format".into(),
+        ))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Ok(StructSerializer { ser: self })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        self.ser.write_i32(variant_index as i32)?;
+        Ok(StructSerializer { ser: self })
+    }
+}
+
+/// Serializes the elements of a sequence, tuple, or tuple variant, which on
+/// the wire are just the fields/elements written back to back (the element
+/// count, if any, was already written by [`Serializer::serialize_seq`]
+/// before this was constructed).
+pub struct SeqSerializer<'a, W: Write> {
+    ser: &'a mut Serializer<W>,
+}
+
+impl<'a, W: Write> ser::SerializeSeq for SeqSerializer<'a, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a, W: Write> ser::SerializeTuple for SeqSerializer<'a, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a, W: Write> ser::SerializeTupleStruct for SeqSerializer<'a, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a, W: Write> ser::SerializeTupleVariant for SeqSerializer<'a, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Serializes the fields of a struct, struct variant, or (as an
+/// unsupported stand-in, see [`Serializer::serialize_map`]) a map.
+///
+/// Struct fields on the wire are just the field values written back to
+/// back in declaration order, matching how Kafka messages lay out their
+/// fields, with no field-name or field-count prefix.
+pub struct StructSerializer<'a, W: Write> {
+    ser: &'a mut Serializer<W>,
+}
+
+impl<'a, W: Write> ser::SerializeStruct for StructSerializer<'a, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a, W: Write> ser::SerializeStructVariant for StructSerializer<'a, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a, W: Write> ser::SerializeMap for StructSerializer<'a, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, _key: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(Error::Message(
+            "maps are not supported by the Kafka wire format".into(),
+        ))
+    }
+
+    fn serialize_value<T>(&mut self, _value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(Error::Message(
+            "maps are not supported by the Kafka wire format".into(),
+        ))
+    }
+
+    fn end(self) -> Result<()> {
+        Err(Error::Message(
+            "maps are not supported by the Kafka wire format".into(),
+        ))
+    }
+}
@@ -0,0 +1,195 @@
+//! This module defines newtype wrappers for Kafka's string primitives
+//! (`string`, `nullable string`, and the compact/flexible-versions
+//! `compact string`). These wrappers implement Serde's `Serialize` and
+//! `Deserialize` traits, providing a Kafka-compatible representation of
+//! the length-prefixed, UTF-8 wire-format string primitives.
+//!
+//! # Important
+//! - Although these types implement `Serialize` and `Deserialize`, the actual
+//!   **length-prefix encoding** you produce or consume will depend on the
+//!   underlying format and serializer. For direct, low-level I/O, see the
+//!   [`KafkaSerializer`] and [`KafkaDeserializer`] structs in `io.rs` within
+//!   this crate.
+
+use crate::String;
+use serde::{Deserialize, Serialize};
+
+/// A newtype for Kafka's non-nullable `string` type: an `i16` big-endian
+/// length followed by that many UTF-8 bytes.
+///
+/// # Serialization
+/// - Uses standard `String` Serde serialization by default.
+/// - For low-level byte I/O using Kafka's length-prefixed encoding, use
+///   [`KafkaSerializer::write_string`][crate::KafkaSerializer::write_string]
+///   and
+///   [`KafkaDeserializer::read_string`][crate::KafkaDeserializer::read_string].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KafkaString(pub String);
+
+impl Serialize for KafkaString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for KafkaString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let val = String::deserialize(deserializer)?;
+        Ok(KafkaString(val))
+    }
+}
+
+/// A newtype for Kafka's `nullable string` type, which uses length `-1` to
+/// represent `None`.
+///
+/// # Serialization
+/// - Uses standard `Option<String>` Serde serialization by default.
+/// - For low-level byte I/O using Kafka's nullable length-prefixed encoding,
+///   use
+///   [`KafkaSerializer::write_nullable_string`][crate::KafkaSerializer::write_nullable_string]
+///   and
+///   [`KafkaDeserializer::read_nullable_string`][crate::KafkaDeserializer::read_nullable_string].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KafkaNullableString(pub Option<String>);
+
+impl Serialize for KafkaNullableString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KafkaNullableString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let val = Option::<String>::deserialize(deserializer)?;
+        Ok(KafkaNullableString(val))
+    }
+}
+
+/// A newtype for Kafka's flexible-versions `compact string` type: an
+/// unsigned varint equal to `length + 1` (where `0` means null) followed by
+/// that many UTF-8 bytes.
+///
+/// # Serialization
+/// - Uses standard `Option<String>` Serde serialization by default.
+/// - For low-level byte I/O using Kafka's compact-string encoding, use
+///   [`KafkaSerializer::write_compact_string`][crate::KafkaSerializer::write_compact_string]
+///   and
+///   [`KafkaDeserializer::read_compact_string`][crate::KafkaDeserializer::read_compact_string].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KafkaCompactString(pub Option<String>);
+
+impl Serialize for KafkaCompactString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KafkaCompactString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let val = Option::<String>::deserialize(deserializer)?;
+        Ok(KafkaCompactString(val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! This module contains unit tests that verify round-trip behavior
+    //! using the custom serializer/deserializer found in `io.rs`.
+
+    use crate::{KafkaDeserializer, KafkaSerializer};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_string_roundtrip() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_string("hello").expect("Failed to write string");
+        }
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        let decoded = de.read_string().expect("Failed to read string");
+        assert_eq!(decoded, "hello");
+    }
+
+    #[test]
+    fn test_nullable_string_roundtrip() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_nullable_string(None)
+                .expect("Failed to write nullable string");
+            ser.write_nullable_string(Some("world"))
+                .expect("Failed to write nullable string");
+        }
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert_eq!(de.read_nullable_string().unwrap(), None);
+        assert_eq!(
+            de.read_nullable_string().unwrap(),
+            Some("world".to_string())
+        );
+    }
+
+    #[test]
+    fn test_compact_string_roundtrip() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_compact_string(None)
+                .expect("Failed to write compact string");
+            ser.write_compact_string(Some("kafka"))
+                .expect("Failed to write compact string");
+        }
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert_eq!(de.read_compact_string(16).unwrap(), None);
+        assert_eq!(
+            de.read_compact_string(16).unwrap(),
+            Some("kafka".to_string())
+        );
+    }
+
+    #[test]
+    fn test_compact_string_rejects_over_cap_length() {
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            ser.write_compact_string(Some("kafka"))
+                .expect("Failed to write compact string");
+        }
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert!(de.read_compact_string(2).is_err());
+    }
+
+    #[test]
+    fn test_string_rejects_invalid_utf8() {
+        // Length 2, followed by an invalid UTF-8 byte sequence.
+        let buffer = vec![0x00, 0x02, 0xFF, 0xFF];
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert!(de.read_string().is_err());
+    }
+
+    #[test]
+    fn test_nullable_string_rejects_negative_length() {
+        // -2 is not a valid length and not the nullable sentinel (-1).
+        let buffer = vec![0xFF, 0xFE];
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert!(de.read_nullable_string().is_err());
+    }
+}
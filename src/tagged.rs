@@ -0,0 +1,142 @@
+//! This module defines [`TaggedFields`], a representation of Kafka's
+//! flexible-versions "tagged fields" section: an unsigned varint count of
+//! tags, then for each tag an unsigned varint tag number, an unsigned
+//! varint byte length, and that many opaque bytes.
+//!
+//! Unlike the other primitive wrapper types in this crate, tagged fields
+//! have no natural mapping onto Serde's data model (they are closer to a
+//! length-prefixed map of raw byte blobs than any single Serde type), so
+//! this module exposes [`TaggedFields::write_to`]/[`TaggedFields::read_from`]
+//! operating directly on [`KafkaSerializer`]/[`KafkaDeserializer`] rather
+//! than a `Serialize`/`Deserialize` impl.
+
+use crate::io::{IoResult, KafkaDeserializer, KafkaRead, KafkaSerializer, KafkaWrite};
+use crate::io::invalid_data;
+use crate::{BTreeMap, Vec};
+
+/// A Kafka flexible-versions "tagged fields" section: a sorted map from tag
+/// number to that tag's opaque, already-encoded value bytes.
+///
+/// Every tag this crate reads back is kept verbatim, known or not — it is
+/// up to the caller to look up the tags it understands and ignore the
+/// rest, which is how flexible-versions forward compatibility works.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct TaggedFields(pub BTreeMap<u32, Vec<u8>>);
+
+impl TaggedFields {
+    /// Writes this tagged-fields section: an unsigned varint count of tags,
+    /// then for each tag, in ascending order, an unsigned varint tag
+    /// number, an unsigned varint byte length, and the opaque value bytes.
+    pub fn write_to<W: KafkaWrite>(&self, ser: &mut KafkaSerializer<W>) -> IoResult<()> {
+        ser.write_uvarint(self.0.len() as u32)?;
+        for (tag, bytes) in &self.0 {
+            ser.write_uvarint(*tag)?;
+            ser.write_uvarint(bytes.len() as u32)?;
+            ser.write_raw_bytes(bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a tagged-fields section written by [`write_to`][Self::write_to].
+    ///
+    /// `max_fields` bounds the declared tag count and `max_field_len` bounds
+    /// each individual field's declared byte length, so a corrupt or
+    /// hostile size cannot trigger an unbounded allocation.
+    pub fn read_from<R: KafkaRead>(
+        de: &mut KafkaDeserializer<R>,
+        max_fields: usize,
+        max_field_len: usize,
+    ) -> IoResult<Self> {
+        let count = de.read_uvarint()? as usize;
+        if count > max_fields {
+            return Err(invalid_data(
+                "tagged fields count exceeds configured maximum",
+            ));
+        }
+        let mut fields = BTreeMap::new();
+        for _ in 0..count {
+            let tag = de.read_uvarint()?;
+            let len = de.read_uvarint()? as usize;
+            let bytes = de.read_raw_bytes(len, max_field_len)?;
+            fields.insert(tag, bytes);
+        }
+        Ok(TaggedFields(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! This module contains unit tests that verify round-trip behavior
+    //! using the custom serializer/deserializer found in `io.rs`.
+
+    use super::TaggedFields;
+    use crate::{KafkaDeserializer, KafkaSerializer};
+    use std::collections::BTreeMap;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_tagged_fields_roundtrip() {
+        let mut fields = BTreeMap::new();
+        fields.insert(0u32, vec![1, 2, 3]);
+        fields.insert(5u32, vec![]);
+        let tagged = TaggedFields(fields);
+
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            tagged.write_to(&mut ser).expect("Failed to write tagged fields");
+        }
+
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        let decoded =
+            TaggedFields::read_from(&mut de, 16, 16).expect("Failed to read tagged fields");
+        assert_eq!(decoded, tagged);
+    }
+
+    #[test]
+    fn test_tagged_fields_empty_roundtrip() {
+        let tagged = TaggedFields::default();
+
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            tagged.write_to(&mut ser).expect("Failed to write tagged fields");
+        }
+
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        let decoded =
+            TaggedFields::read_from(&mut de, 16, 16).expect("Failed to read tagged fields");
+        assert_eq!(decoded, tagged);
+    }
+
+    #[test]
+    fn test_tagged_fields_preserves_unknown_tags() {
+        // A caller that only understands tag 0 should still be able to
+        // read back tag 7's raw bytes unharmed, rather than erroring or
+        // silently dropping them.
+        let mut fields = BTreeMap::new();
+        fields.insert(0u32, vec![9]);
+        fields.insert(7u32, vec![0xAA, 0xBB]);
+        let tagged = TaggedFields(fields);
+
+        let mut buffer = Vec::new();
+        {
+            let mut ser = KafkaSerializer::new(&mut buffer);
+            tagged.write_to(&mut ser).expect("Failed to write tagged fields");
+        }
+
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        let decoded =
+            TaggedFields::read_from(&mut de, 16, 16).expect("Failed to read tagged fields");
+        assert_eq!(decoded.0.get(&7), Some(&vec![0xAA, 0xBB]));
+    }
+
+    #[test]
+    fn test_tagged_fields_rejects_over_cap_count() {
+        // Unsigned varint count of 5, exceeding the max_fields cap below.
+        let buffer = vec![0x05];
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        let result = TaggedFields::read_from(&mut de, 2, 16);
+        assert!(result.is_err());
+    }
+}
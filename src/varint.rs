@@ -0,0 +1,144 @@
+//! This module defines newtype wrappers for Kafka's variable-length integer
+//! types (`varint`, `varlong`). These wrappers implement Serde's `Serialize`
+//! and `Deserialize` traits, providing a Kafka-compatible representation of
+//! the zigzag-encoded wire-format integer primitives.
+//!
+//! # Important
+//! - Although these types implement `Serialize` and `Deserialize`, the actual
+//!   **zigzag/7-bit encoding** you produce or consume will depend on the
+//!   underlying format and serializer. For direct, low-level I/O, see the
+//!   [`KafkaSerializer`] and [`KafkaDeserializer`] structs in `io.rs` within
+//!   this crate.
+
+use serde::{Deserialize, Serialize};
+
+/// A newtype for Kafka's variable-length, zigzag-encoded 32-bit integer
+/// (`varint`).
+///
+/// # Serialization
+/// - Uses standard `i32` Serde serialization by default.
+/// - For low-level byte I/O using Kafka's zigzag varint encoding, use
+///   [`KafkaSerializer::write_varint`][crate::KafkaSerializer::write_varint]
+///   and
+///   [`KafkaDeserializer::read_varint`][crate::KafkaDeserializer::read_varint].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct KafkaVarInt(pub i32);
+
+impl Serialize for KafkaVarInt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // We delegate to Serde's built-in i32 handling here.
+        serializer.serialize_i32(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for KafkaVarInt {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let val = i32::deserialize(deserializer)?;
+        Ok(KafkaVarInt(val))
+    }
+}
+
+/// A newtype for Kafka's variable-length, zigzag-encoded 64-bit integer
+/// (`varlong`).
+///
+/// # Serialization
+/// - Uses standard `i64` Serde serialization by default.
+/// - For low-level byte I/O using Kafka's zigzag varlong encoding, use
+///   [`KafkaSerializer::write_varlong`][crate::KafkaSerializer::write_varlong]
+///   and
+///   [`KafkaDeserializer::read_varlong`][crate::KafkaDeserializer::read_varlong].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct KafkaVarLong(pub i64);
+
+impl Serialize for KafkaVarLong {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_i64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for KafkaVarLong {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let val = i64::deserialize(deserializer)?;
+        Ok(KafkaVarLong(val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! This module contains unit tests that verify round-trip behavior
+    //! using the custom serializer/deserializer found in `io.rs`.
+
+    use crate::{KafkaDeserializer, KafkaSerializer};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_varint_roundtrip() {
+        for val in [0, 1, -1, 63, 64, -64, -65, i32::MAX, i32::MIN] {
+            let mut buffer = Vec::new();
+            {
+                let mut ser = KafkaSerializer::new(&mut buffer);
+                ser.write_varint(val).expect("Failed to write varint");
+            }
+            let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+            let decoded = de.read_varint().expect("Failed to read varint");
+            assert_eq!(decoded, val);
+        }
+    }
+
+    #[test]
+    fn test_varlong_roundtrip() {
+        for val in [0, 1, -1, 63, 64, -64, -65, i64::MAX, i64::MIN] {
+            let mut buffer = Vec::new();
+            {
+                let mut ser = KafkaSerializer::new(&mut buffer);
+                ser.write_varlong(val).expect("Failed to write varlong");
+            }
+            let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+            let decoded = de.read_varlong().expect("Failed to read varlong");
+            assert_eq!(decoded, val);
+        }
+    }
+
+    #[test]
+    fn test_varint_overflow() {
+        // 5 bytes, all with continuation bit set -> exceeds the 5-byte cap.
+        let buffer = vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert!(de.read_varint().is_err());
+    }
+
+    #[test]
+    fn test_varint_rejects_value_that_does_not_fit_in_32_bits() {
+        // A fully-terminated 5-byte encoding (no 6th byte needed) whose
+        // decoded value is 34_359_738_367 -- far above u32::MAX -- must be
+        // rejected rather than silently truncated.
+        let buffer = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x7F];
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert!(de.read_varint().is_err());
+    }
+
+    #[test]
+    fn test_varlong_rejects_value_that_does_not_fit_in_64_bits() {
+        // A fully-terminated 10-byte encoding whose final 7-bit group has
+        // more than the single bit that fits at bit 63 of a u64 must be
+        // rejected rather than silently truncated.
+        let buffer = vec![0xFF; 9]
+            .into_iter()
+            .chain([0x7F])
+            .collect::<Vec<u8>>();
+        let mut de = KafkaDeserializer::new(Cursor::new(&buffer));
+        assert!(de.read_varlong().is_err());
+    }
+}